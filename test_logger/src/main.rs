@@ -1,15 +1,133 @@
 use std::fs::OpenOptions;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 use std::path::PathBuf;
 use chrono::{Local, Utc, DateTime, Duration};
 use clap::{App, Arg};
-use std::collections::HashMap;
-use rustyline::{DefaultEditor, Result};
+use std::collections::{HashMap, HashSet};
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+use serde::Serialize;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+enum LoggerError {
+    #[error("I/O error while {context}: {source}")]
+    Io {
+        context: String,
+        #[source]
+        source: io::Error,
+    },
+    #[error("failed to read input while {context}: {source}")]
+    Readline {
+        context: String,
+        #[source]
+        source: ReadlineError,
+    },
+    #[error("could not create or write to log directory {0}")]
+    LogDir(PathBuf),
+    #[error("{0}")]
+    TimeFormat(String),
+}
+
+trait Context<T> {
+    fn context(self, context: impl Into<String>) -> Result<T, LoggerError>;
+}
+
+impl<T> Context<T> for io::Result<T> {
+    fn context(self, context: impl Into<String>) -> Result<T, LoggerError> {
+        self.map_err(|source| LoggerError::Io { context: context.into(), source })
+    }
+}
+
+impl<T> Context<T> for Result<T, ReadlineError> {
+    fn context(self, context: impl Into<String>) -> Result<T, LoggerError> {
+        self.map_err(|source| LoggerError::Readline { context: context.into(), source })
+    }
+}
+
+const COLOR_RED: &str = "\x1B[31;1m";
+const COLOR_YELLOW: &str = "\x1B[33;1m";
+const COLOR_GREEN: &str = "\x1B[32;1m";
+const COLOR_BLUE: &str = "\x1B[34;1m";
+const COLOR_RESET: &str = "\x1B[1;0m";
+
+fn color_for_tag(tag: &str) -> Option<&'static str> {
+    match tag {
+        "BUG" | "FAIL" => Some(COLOR_RED),
+        "WARN" => Some(COLOR_YELLOW),
+        "GOOD" | "PASS" => Some(COLOR_GREEN),
+        "VERSION" => Some(COLOR_BLUE),
+        _ => None,
+    }
+}
+
+fn colorize(text: &str, tag: &str, use_color: bool) -> String {
+    if !use_color {
+        return text.to_string();
+    }
+    match color_for_tag(tag) {
+        Some(color) => format!("{}{}{}", color, text, COLOR_RESET),
+        None => text.to_string(),
+    }
+}
 
-fn get_timestamp() -> (String, String, DateTime<Local>) {
+#[derive(Clone, Copy)]
+enum ClockSource {
+    Local,
+    Utc,
+    Monotonic,
+}
+
+impl ClockSource {
+    fn parse(value: &str) -> ClockSource {
+        match value {
+            "utc" => ClockSource::Utc,
+            "monotonic" => ClockSource::Monotonic,
+            _ => ClockSource::Local,
+        }
+    }
+}
+
+// With `ClockSource::Monotonic`, local_time/utc_time both carry elapsed seconds since session start, not wall time.
+struct TimeFormat {
+    format: String,
+    clock: ClockSource,
+}
+
+impl TimeFormat {
+    fn validate(format: &str) -> std::result::Result<(), String> {
+        use chrono::format::Item;
+        let items: Vec<Item> = chrono::format::StrftimeItems::new(format).collect();
+        if items.iter().any(|item| matches!(item, Item::Error)) {
+            Err(format!("invalid --time-format pattern: {}", format))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn stamp(&self, now_local: DateTime<Local>, now_utc: DateTime<Utc>, session_start: DateTime<Local>) -> (String, String) {
+        match self.clock {
+            ClockSource::Local => {
+                let s = now_local.format(&self.format).to_string();
+                (s.clone(), s)
+            }
+            ClockSource::Utc => {
+                let s = now_utc.format(&self.format).to_string();
+                (s.clone(), s)
+            }
+            ClockSource::Monotonic => {
+                let s = format!("{}s", (now_local - session_start).num_seconds());
+                (s.clone(), s)
+            }
+        }
+    }
+}
+
+fn get_timestamp(time_format: &TimeFormat, session_start: Option<DateTime<Local>>) -> (String, String, DateTime<Local>) {
     let local_time = Local::now();
-    let local_str = local_time.format("%Y-%m-%d %H:%M:%S").to_string();
-    let utc_str = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let utc_time = Utc::now();
+    let start = session_start.unwrap_or(local_time);
+    let (local_str, utc_str) = time_format.stamp(local_time, utc_time, start);
     (local_str, utc_str, local_time)
 }
 
@@ -17,12 +135,13 @@ fn get_filename_timestamp() -> String {
     Local::now().format("%Y%m%d_%H%M%S").to_string()
 }
 
+#[derive(Serialize)]
 struct LogEntry {
     local_time: String,
     utc_time: String,
     entry_type: String,
-    description: String,
     tag: String,
+    description: String,
 }
 
 fn parse_input(input: &str) -> (String, String, String) {
@@ -47,118 +166,344 @@ fn parse_input(input: &str) -> (String, String, String) {
     }
 }
 
-fn write_logs_txt(
-    logs: &Vec<LogEntry>,
-    filepath: &PathBuf,
-    test_name: &str,
-    software_version: &str,
-    test_objective: &str,
-    test_operator: &str,
-    participating_assets: &str,
-    start_time: &str,
-    end_time: &str,
-    duration: &Duration,
-    tag_counts: &HashMap<String, u32>,
-) -> io::Result<()> {
-    let mut file = OpenOptions::new().write(true).create(true).truncate(true).open(filepath)?;
-
-    writeln!(file, "--- Test Session Started ---")?;
-    writeln!(file, "Test Name            : {}", test_name)?;
-    writeln!(file, "Software Version and Hash     : {}", software_version)?;
-    writeln!(file, "Test Objective       : {}", test_objective)?;
-    writeln!(file, "Test Operator        : {}", test_operator)?;
-    writeln!(file, "Participating Asset(s) : {}", participating_assets)?;
-    writeln!(file, "Start Time           : {}", start_time)?;
-    writeln!(file, "---------------------------------------\n")?;
-
-    writeln!(file, "--- Log Entries ---")?;
-
-    for log in logs {
-        let log_line = format!(
-            "[Local: {}] [UTC: {}] [{}]\t[{}]\t{}",
-            log.local_time,
-            log.utc_time,
-            log.entry_type.trim(),
-            log.tag.trim(),
-            log.description
-        );
-        writeln!(file, "{}", log_line)?;
-    }
-
-    writeln!(file, "\n--- Test Session Ended ---")?;
-    writeln!(file, "End Time             : {}", end_time)?;
-    writeln!(file, "Duration             : {} minutes {} seconds", duration.num_minutes(), duration.num_seconds() % 60)?;
-    writeln!(file, "---------------------------------------")?;
-    writeln!(file, "Summary of Tags:")?;
+fn severity_rank(tag: &str) -> u8 {
+    match tag {
+        "FAIL" | "BUG" => 2,
+        "WARN" => 1,
+        _ => 0,
+    }
+}
+
+// TEST START/TEST END markers always pass so session boundaries are never lost.
+fn entry_passes_filter(entry_type: &str, tag: &str, min_severity: u8, ignore_tags: &HashSet<String>) -> bool {
+    if entry_type == "TEST START" || entry_type == "TEST END" {
+        return true;
+    }
+    let tag_upper = tag.trim().to_uppercase();
+    if ignore_tags.contains(&tag_upper) {
+        return false;
+    }
+    severity_rank(&tag_upper) >= min_severity
+}
+
+struct SessionMeta {
+    test_name: String,
+    software_version: String,
+    test_objective: String,
+    test_operator: String,
+    participating_assets: String,
+    start_time: String,
+}
+
+fn format_txt_header(meta: &SessionMeta) -> String {
+    format!(
+        "--- Test Session Started ---\n\
+         Test Name            : {}\n\
+         Software Version and Hash     : {}\n\
+         Test Objective       : {}\n\
+         Test Operator        : {}\n\
+         Participating Asset(s) : {}\n\
+         Start Time           : {}\n\
+         ---------------------------------------\n\n\
+         --- Log Entries ---\n",
+        meta.test_name, meta.software_version, meta.test_objective, meta.test_operator, meta.participating_assets, meta.start_time
+    )
+}
+
+fn format_txt_entry(log: &LogEntry) -> String {
+    format!(
+        "[Local: {}] [UTC: {}] [{}]\t[{}]\t{}\n",
+        log.local_time,
+        log.utc_time,
+        log.entry_type.trim(),
+        log.tag.trim(),
+        log.description
+    )
+}
+
+fn format_txt_summary(end_time: &str, duration: &Duration, tag_counts: &HashMap<String, u32>) -> String {
+    let mut out = format!(
+        "\n--- Test Session Ended ---\n\
+         End Time             : {}\n\
+         Duration             : {} minutes {} seconds\n\
+         ---------------------------------------\n\
+         Summary of Tags:\n",
+        end_time,
+        duration.num_minutes(),
+        duration.num_seconds() % 60
+    );
     for (tag, count) in tag_counts {
-        writeln!(file, "{:<10}: {}", tag, count)?;
+        out.push_str(&format!("{:<10}: {}\n", tag, count));
     }
-    writeln!(file, "---------------------------------------")?;
+    out.push_str("---------------------------------------\n");
+    out
+}
 
-    Ok(())
+fn format_csv_header(meta: &SessionMeta) -> String {
+    format!(
+        "Test Name,{}\n\
+         Software Version,{}\n\
+         Test Objective,{}\n\
+         Test Operator,{}\n\
+         Participating Asset(s),{}\n\
+         Start Time,{}\n\
+         \n\
+         Local Time,UTC Time,Entry Type,Tag,Description\n",
+        meta.test_name, meta.software_version, meta.test_objective, meta.test_operator, meta.participating_assets, meta.start_time
+    )
 }
 
-fn write_logs_csv(
-    logs: &Vec<LogEntry>,
-    filepath: &PathBuf,
-    test_name: &str,
-    software_version: &str,
-    test_objective: &str,
-    test_operator: &str,
-    participating_assets: &str,
-    start_time: &str,
-    end_time: &str,
-    duration: &Duration,
-    tag_counts: &HashMap<String, u32>,
-) -> io::Result<()> {
-    let mut file = OpenOptions::new().write(true).create(true).truncate(true).open(filepath)?;
-
-    writeln!(file, "Test Name,{}", test_name)?;
-    writeln!(file, "Software Version,{}", software_version)?;
-    writeln!(file, "Test Objective,{}", test_objective)?;
-    writeln!(file, "Test Operator,{}", test_operator)?;
-    writeln!(file, "Participating Asset(s),{}", participating_assets)?;
-    writeln!(file, "Start Time,{}", start_time)?;
-    writeln!(file)?;
-
-    writeln!(file, "Local Time,UTC Time,Entry Type,Tag,Description")?;
-    for log in logs {
-        let log_line = format!(
-            "\"{}\",\"{}\",\"{}\",\"{}\",\"{}\"",
-            log.local_time,
-            log.utc_time,
-            log.entry_type.trim(),
-            log.tag.trim(),
-            log.description
-        );
-        writeln!(file, "{}", log_line)?;
-    }
-
-    writeln!(file)?;
-    writeln!(file, "Summary Information")?;
-    writeln!(file, "End Time,{}", end_time)?;
-    writeln!(file, "Duration,{} minutes {} seconds", duration.num_minutes(), duration.num_seconds() % 60)?;
-    writeln!(file)?;
-    writeln!(file, "Tag,Count")?;
+fn format_csv_entry(log: &LogEntry) -> String {
+    format!(
+        "\"{}\",\"{}\",\"{}\",\"{}\",\"{}\"\n",
+        log.local_time,
+        log.utc_time,
+        log.entry_type.trim(),
+        log.tag.trim(),
+        log.description
+    )
+}
 
+fn format_csv_summary(end_time: &str, duration: &Duration, tag_counts: &HashMap<String, u32>) -> String {
+    let mut out = format!(
+        "\n\
+         Summary Information\n\
+         End Time,{}\n\
+         Duration,{} minutes {} seconds\n\
+         \n\
+         Tag,Count\n",
+        end_time,
+        duration.num_minutes(),
+        duration.num_seconds() % 60
+    );
     for (tag, count) in tag_counts {
-        writeln!(file, "{},{}", tag, count)?;
+        out.push_str(&format!("{},{}\n", tag, count));
     }
+    out
+}
 
-    Ok(())
+#[derive(Serialize)]
+struct JsonSessionHeader<'a> {
+    test_name: &'a str,
+    software_version: &'a str,
+    test_objective: &'a str,
+    test_operator: &'a str,
+    participating_assets: &'a str,
+    start_time: &'a str,
+}
+
+#[derive(Serialize)]
+struct JsonSessionSummary<'a> {
+    end_time: &'a str,
+    duration_seconds: i64,
+    tag_counts: &'a HashMap<String, u32>,
+}
+
+fn jsonl_line<T: Serialize>(value: &T) -> io::Result<String> {
+    serde_json::to_string(value)
+        .map(|line| line + "\n")
+        .map_err(io::Error::other)
+}
+
+fn format_jsonl_header(meta: &SessionMeta) -> io::Result<String> {
+    jsonl_line(&JsonSessionHeader {
+        test_name: &meta.test_name,
+        software_version: &meta.software_version,
+        test_objective: &meta.test_objective,
+        test_operator: &meta.test_operator,
+        participating_assets: &meta.participating_assets,
+        start_time: &meta.start_time,
+    })
+}
+
+fn format_jsonl_entry(log: &LogEntry) -> io::Result<String> {
+    jsonl_line(log)
+}
+
+fn format_jsonl_summary(end_time: &str, duration: &Duration, tag_counts: &HashMap<String, u32>) -> io::Result<String> {
+    jsonl_line(&JsonSessionSummary {
+        end_time,
+        duration_seconds: duration.num_seconds(),
+        tag_counts,
+    })
+}
+
+// Writes and flushes to disk as soon as data arrives, so entries already written survive a
+// crash mid-session; once a segment has reached `capacity` bytes, the next entry opens a numbered sibling file.
+struct RotatingLogFile {
+    base_path: PathBuf,
+    extension: &'static str,
+    capacity: u64,
+    segment: u32,
+    bytes_written: u64,
+    file: std::fs::File,
+}
+
+impl RotatingLogFile {
+    fn segment_path(base_path: &std::path::Path, extension: &str, segment: u32) -> PathBuf {
+        let mut name = base_path.as_os_str().to_os_string();
+        if segment > 0 {
+            name.push(format!(".{}", segment));
+        }
+        name.push(".");
+        name.push(extension);
+        PathBuf::from(name)
+    }
+
+    fn open(base_path: PathBuf, extension: &'static str, capacity: u64, header: &str) -> io::Result<Self> {
+        let path = Self::segment_path(&base_path, extension, 0);
+        let file = OpenOptions::new().write(true).create(true).truncate(true).open(&path)?;
+        let mut writer = RotatingLogFile { base_path, extension, capacity, segment: 0, bytes_written: 0, file };
+        writer.write_str(header)?;
+        Ok(writer)
+    }
+
+    fn current_path(&self) -> PathBuf {
+        Self::segment_path(&self.base_path, self.extension, self.segment)
+    }
+
+    fn write_str(&mut self, data: &str) -> io::Result<()> {
+        self.file.write_all(data.as_bytes())?;
+        self.file.flush()?;
+        self.bytes_written += data.len() as u64;
+        Ok(())
+    }
+
+    fn append(&mut self, entry: &str, header: &str) -> io::Result<()> {
+        if self.bytes_written >= self.capacity {
+            self.segment += 1;
+            let path = self.current_path();
+            self.file = OpenOptions::new().write(true).create(true).truncate(true).open(&path)?;
+            self.bytes_written = 0;
+            self.write_str(header)?;
+        }
+        self.write_str(entry)
+    }
+}
+
+struct SessionWriters {
+    txt: Option<RotatingLogFile>,
+    csv: Option<RotatingLogFile>,
+    jsonl: Option<RotatingLogFile>,
+    header_txt: String,
+    header_csv: String,
+    header_jsonl: String,
+}
+
+impl SessionWriters {
+    fn open(formats: &HashSet<String>, log_base: &std::path::Path, capacity: u64, meta: &SessionMeta) -> io::Result<Self> {
+        let header_txt = format_txt_header(meta);
+        let header_csv = format_csv_header(meta);
+        let header_jsonl = format_jsonl_header(meta)?;
+
+        let txt = if formats.contains("txt") {
+            Some(RotatingLogFile::open(log_base.to_path_buf(), "txt", capacity, &header_txt)?)
+        } else {
+            None
+        };
+        let csv = if formats.contains("csv") {
+            Some(RotatingLogFile::open(log_base.to_path_buf(), "csv", capacity, &header_csv)?)
+        } else {
+            None
+        };
+        let jsonl = if formats.contains("json") {
+            Some(RotatingLogFile::open(log_base.to_path_buf(), "jsonl", capacity, &header_jsonl)?)
+        } else {
+            None
+        };
+
+        Ok(SessionWriters { txt, csv, jsonl, header_txt, header_csv, header_jsonl })
+    }
+
+    fn append(&mut self, entry: &LogEntry) -> io::Result<()> {
+        if let Some(w) = self.txt.as_mut() {
+            w.append(&format_txt_entry(entry), &self.header_txt)?;
+        }
+        if let Some(w) = self.csv.as_mut() {
+            w.append(&format_csv_entry(entry), &self.header_csv)?;
+        }
+        if let Some(w) = self.jsonl.as_mut() {
+            w.append(&format_jsonl_entry(entry)?, &self.header_jsonl)?;
+        }
+        Ok(())
+    }
+
+    fn write_summary(&mut self, end_time: &str, duration: &Duration, tag_counts: &HashMap<String, u32>) -> io::Result<()> {
+        if let Some(w) = self.txt.as_mut() {
+            w.write_str(&format_txt_summary(end_time, duration, tag_counts))?;
+        }
+        if let Some(w) = self.csv.as_mut() {
+            w.write_str(&format_csv_summary(end_time, duration, tag_counts))?;
+        }
+        if let Some(w) = self.jsonl.as_mut() {
+            w.write_str(&format_jsonl_summary(end_time, duration, tag_counts)?)?;
+        }
+        Ok(())
+    }
+
+    fn paths(&self) -> Vec<PathBuf> {
+        [&self.txt, &self.csv, &self.jsonl]
+            .into_iter()
+            .filter_map(|w| w.as_ref().map(RotatingLogFile::current_path))
+            .collect()
+    }
+}
+
+const APP_NAME: &str = "test-logger";
+
+fn preferred_log_dir() -> PathBuf {
+    if cfg!(target_os = "windows") {
+        let program_data = std::env::var("PROGRAMDATA").unwrap_or_else(|_| "C:\\ProgramData".to_string());
+        PathBuf::from(program_data).join(APP_NAME)
+    } else if cfg!(target_os = "macos") {
+        let home = std::env::var("HOME").unwrap_or_else(|_| "/".to_string());
+        PathBuf::from(home).join("Library").join("Logs").join(APP_NAME)
+    } else {
+        PathBuf::from("/var/log").join(APP_NAME)
+    }
+}
+
+fn try_log_dir(dir: &std::path::Path) -> Option<PathBuf> {
+    if std::fs::create_dir_all(dir).is_err() {
+        return None;
+    }
+    let probe = dir.join(".write_test");
+    match std::fs::File::create(&probe) {
+        Ok(_) => {
+            let _ = std::fs::remove_file(&probe);
+            Some(dir.to_path_buf())
+        }
+        Err(_) => None,
+    }
 }
 
+// Tries the platform-conventional location first, then next to the executable, then the temp dir.
 fn get_default_log_dir() -> PathBuf {
-    let exe_path = std::env::current_exe().unwrap();
-    let exe_dir = exe_path.parent().unwrap();
-    let log_dir = exe_dir.join("logs");
+    let exe_adjacent = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.join("logs")));
+
+    let candidates = std::iter::once(preferred_log_dir())
+        .chain(exe_adjacent)
+        .chain(std::iter::once(std::env::temp_dir().join(APP_NAME)));
 
-    std::fs::create_dir_all(&log_dir).expect("Failed to create logs directory");
+    for candidate in candidates {
+        if let Some(dir) = try_log_dir(&candidate) {
+            return dir;
+        }
+    }
+
+    std::env::temp_dir()
+}
 
-    log_dir
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
 }
 
-fn main() -> Result<()> {
+fn run() -> Result<(), LoggerError> {
     let matches = App::new("Test Logger")
         .version("1.0")
         .author("Isaac")
@@ -169,42 +514,136 @@ fn main() -> Result<()> {
             .value_name("DIR")
             .help("Sets a custom log directory")
             .takes_value(true))
+        .arg(Arg::with_name("no_color")
+            .long("no-color")
+            .help("Disable colorized terminal echo, even when stdout is a TTY")
+            .takes_value(false))
+        .arg(Arg::with_name("file_capacity")
+            .long("file-capacity")
+            .value_name("BYTES")
+            .help("Bytes per log segment after which further entries roll over to a numbered sibling file")
+            .takes_value(true)
+            .default_value("64000")
+            .validator(|s| {
+                s.parse::<u64>()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .map(|_| ())
+                    .ok_or_else(|| format!("--file-capacity must be a positive integer number of bytes, got '{}'", s))
+            }))
+        .arg(Arg::with_name("min_severity")
+            .long("min-severity")
+            .value_name("LEVEL")
+            .help("Minimum severity an entry must have to be recorded: note, warn, or fail")
+            .takes_value(true)
+            .possible_values(&["note", "warn", "fail"])
+            .default_value("note"))
+        .arg(Arg::with_name("ignore_tags")
+            .long("ignore-tags")
+            .value_name("CSV")
+            .help("Comma-separated tags to drop regardless of severity (e.g. NOTE,WARN)")
+            .takes_value(true))
+        .arg(Arg::with_name("time_format")
+            .long("time-format")
+            .value_name("STRFTIME")
+            .help("strftime pattern used to render local_time/utc_time")
+            .takes_value(true)
+            .default_value("%Y-%m-%d %H:%M:%S"))
+        .arg(Arg::with_name("clock")
+            .long("clock")
+            .value_name("SOURCE")
+            .help("Clock to stamp entries with: local, utc, or monotonic")
+            .takes_value(true)
+            .possible_values(&["local", "utc", "monotonic"])
+            .default_value("local"))
+        .arg(Arg::with_name("format")
+            .long("format")
+            .value_name("CSV")
+            .help("Comma-separated output formats to write: txt, csv, json")
+            .takes_value(true)
+            .default_value("txt,csv")
+            .validator(|csv| {
+                for format in csv.split(',').map(|s| s.trim()) {
+                    if !["txt", "csv", "json"].contains(&format) {
+                        return Err(format!("unknown --format value '{}' (expected txt, csv, or json)", format));
+                    }
+                }
+                Ok(())
+            }))
         .get_matches();
 
-    let mut rl = DefaultEditor::new()?;
+    let use_color = !matches.is_present("no_color") && io::stdout().is_terminal();
 
-    let test_operator = rl.readline("Enter the test operator's name: ")?.trim().to_string();
-    let test_name = rl.readline("Enter the test name: ")?.trim().to_string();
-    let software_version = rl.readline("Enter the software version being tested: ")?.trim().to_string();
-    let test_objective = rl.readline("Enter the test objective: ")?.trim().to_string();
-    let participating_assets = rl.readline("Enter the participating asset(s): ")?.trim().to_string();
+    // Already validated by the `file_capacity` arg's clap validator above.
+    let file_capacity: u64 = matches.value_of("file_capacity").unwrap().parse().unwrap();
 
-    let filename_timestamp = get_filename_timestamp();
+    let min_severity = severity_rank(&matches.value_of("min_severity").unwrap().to_uppercase());
 
-    let log_dir = matches.value_of("log_dir")
-        .map(PathBuf::from)
-        .unwrap_or_else(|| get_default_log_dir());
+    let ignore_tags: HashSet<String> = matches.value_of("ignore_tags")
+        .map(|csv| csv.split(',').map(|s| s.trim().to_uppercase()).filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
 
-    let mut log_path_txt = log_dir.clone();
-    let mut log_path_csv = log_dir.clone();
+    let time_format_str = matches.value_of("time_format").unwrap().to_string();
+    TimeFormat::validate(&time_format_str).map_err(LoggerError::TimeFormat)?;
+    let time_format = TimeFormat {
+        format: time_format_str,
+        clock: ClockSource::parse(matches.value_of("clock").unwrap()),
+    };
 
-    let log_filename_txt = format!("{}_{}_log.txt", filename_timestamp, test_name.replace(" ", "_"));
-    let log_filename_csv = format!("{}_{}_log.csv", filename_timestamp, test_name.replace(" ", "_"));
+    let formats: HashSet<String> = matches.value_of("format").unwrap()
+        .split(',')
+        .map(|s| s.trim().to_lowercase())
+        .filter(|s| !s.is_empty())
+        .collect();
 
-    log_path_txt.push(log_filename_txt);
-    log_path_csv.push(log_filename_csv);
+    let mut rl = DefaultEditor::new().context("initializing the interactive prompt")?;
 
-    println!("\n--- Log Files Will Be Saved To ---");
-    println!("TXT Log Path : {}", log_path_txt.display());
-    println!("CSV Log Path : {}", log_path_csv.display());
-    println!("----------------------------------\n");
+    let test_operator = rl.readline("Enter the test operator's name: ")
+        .context("reading the test operator prompt")?.trim().to_string();
+    let test_name = rl.readline("Enter the test name: ")
+        .context("reading the test name prompt")?.trim().to_string();
+    let software_version = rl.readline("Enter the software version being tested: ")
+        .context("reading the software version prompt")?.trim().to_string();
+    let test_objective = rl.readline("Enter the test objective: ")
+        .context("reading the test objective prompt")?.trim().to_string();
+    let participating_assets = rl.readline("Enter the participating asset(s): ")
+        .context("reading the participating asset(s) prompt")?.trim().to_string();
+
+    let filename_timestamp = get_filename_timestamp();
+
+    let log_dir = match matches.value_of("log_dir") {
+        Some(custom) => try_log_dir(std::path::Path::new(custom))
+            .ok_or_else(|| LoggerError::LogDir(PathBuf::from(custom)))?,
+        None => get_default_log_dir(),
+    };
+
+    println!("Using log directory: {}", log_dir.display());
+
+    let log_base = log_dir.join(format!("{}_{}_log", filename_timestamp, test_name.replace(" ", "_")));
 
-    let mut logs: Vec<LogEntry> = Vec::new();
     let mut tag_counts: HashMap<String, u32> = HashMap::new();
 
-    let (start_local, _start_utc, start_dt) = get_timestamp();
+    let (start_local, _start_utc, start_dt) = get_timestamp(&time_format, None);
     let start_time = start_local.clone();
 
+    let meta = SessionMeta {
+        test_name: test_name.clone(),
+        software_version: software_version.clone(),
+        test_objective: test_objective.clone(),
+        test_operator: test_operator.clone(),
+        participating_assets: participating_assets.clone(),
+        start_time: start_time.clone(),
+    };
+
+    let mut writers = SessionWriters::open(&formats, &log_base, file_capacity, &meta)
+        .context(format!("opening log files in {}", log_dir.display()))?;
+
+    println!("\n--- Log Files Will Be Saved To ---");
+    for path in writers.paths() {
+        println!("{}", path.display());
+    }
+    println!("----------------------------------\n");
+
     println!("--- Test Session Started ---");
     println!("Test Operator        : {}", test_operator);
     println!("Test Name            : {}", test_name);
@@ -226,16 +665,17 @@ fn main() -> Result<()> {
     println!("To END the test, type 'end' and press Enter.");
     println!("---------------------------------------\n");
 
-    logs.push(LogEntry {
+    let test_start_entry = LogEntry {
         local_time: start_local,
         utc_time: _start_utc,
         entry_type: "TEST START".to_string(),
         description: "Test started".to_string(),
         tag: "NOTE".to_string(),
-    });
+    };
+    writers.append(&test_start_entry).context("writing session start entry")?;
 
     loop {
-        let input = rl.readline("> ")?;
+        let input = rl.readline("> ").context("reading the log entry prompt")?;
         let behavior = input.trim();
 
         if behavior.is_empty() {
@@ -247,29 +687,36 @@ fn main() -> Result<()> {
         }
 
         let (entry_type, tag, desc) = parse_input(behavior);
-        let (local, utc, _) = get_timestamp();
-
-        logs.push(LogEntry {
-            local_time: local,
-            utc_time: utc,
-            entry_type: entry_type.clone(),
-            description: desc,
-            tag: tag.clone(),
-        });
-
-        *tag_counts.entry(tag).or_insert(0) += 1;
+        let (local, utc, _) = get_timestamp(&time_format, Some(start_dt));
+
+        let echo_line = format!("[{}] [{}] {}", entry_type.trim(), tag.trim(), desc);
+        println!("{}", colorize(&echo_line, &tag, use_color));
+
+        if entry_passes_filter(&entry_type, &tag, min_severity, &ignore_tags) {
+            let entry = LogEntry {
+                local_time: local,
+                utc_time: utc,
+                entry_type: entry_type.clone(),
+                description: desc,
+                tag: tag.clone(),
+            };
+            writers.append(&entry).context("writing log entry")?;
+
+            *tag_counts.entry(tag).or_insert(0) += 1;
+        }
     }
 
-    let (end_local, _end_utc, end_dt) = get_timestamp();
+    let (end_local, _end_utc, end_dt) = get_timestamp(&time_format, Some(start_dt));
     let elapsed = end_dt - start_dt;
 
-    logs.push(LogEntry {
+    let test_end_entry = LogEntry {
         local_time: end_local.clone(),
         utc_time: _end_utc,
         entry_type: "TEST END".to_string(),
         description: "Test session ended.".to_string(),
         tag: "NOTE".to_string(),
-    });
+    };
+    writers.append(&test_end_entry).context("writing session end entry")?;
 
     println!("\n--- Test Session Ended ---");
     println!("End Time             : {}", end_local);
@@ -281,37 +728,12 @@ fn main() -> Result<()> {
     }
     println!("---------------------------------------\n");
 
-    write_logs_txt(
-        &logs,
-        &log_path_txt,
-        &test_name,
-        &software_version,
-        &test_objective,
-        &test_operator,
-        &participating_assets,
-        &start_time,
-        &end_local,
-        &elapsed,
-        &tag_counts,
-    )?;
-
-    write_logs_csv(
-        &logs,
-        &log_path_csv,
-        &test_name,
-        &software_version,
-        &test_objective,
-        &test_operator,
-        &participating_assets,
-        &start_time,
-        &end_local,
-        &elapsed,
-        &tag_counts,
-    )?;
+    writers.write_summary(&end_local, &elapsed, &tag_counts).context("writing session summary")?;
 
     println!("Logs successfully saved!");
-    println!("TXT Log Path : {}", log_path_txt.display());
-    println!("CSV Log Path : {}", log_path_csv.display());
+    for path in writers.paths() {
+        println!("{}", path.display());
+    }
 
     Ok(())
 }